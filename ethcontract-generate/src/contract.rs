@@ -0,0 +1,75 @@
+use crate::generate::{self, Context};
+use anyhow::{Context as _, Result};
+use ethcontract_common::Contract;
+use proc_macro2::TokenStream;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use syn::Ident;
+
+/// Builder for configuring and generating a contract's Rust bindings.
+#[derive(Default)]
+pub struct ContractBuilder {
+    method_aliases: HashMap<String, Ident>,
+    generate_output_structs: bool,
+}
+
+impl ContractBuilder {
+    /// Creates a new builder with default options.
+    pub fn new() -> Self {
+        ContractBuilder::default()
+    }
+
+    /// Adds a manual method name override for the method with the given ABI
+    /// signature, overriding the name that would otherwise be derived from
+    /// the Solidity function name.
+    pub fn add_method_alias(mut self, signature: impl Into<String>, alias: impl AsRef<str>) -> Self {
+        let ident =
+            syn::parse_str(alias.as_ref()).expect("method alias is not a valid Rust identifier");
+        self.method_aliases.insert(signature.into(), ident);
+        self
+    }
+
+    /// When enabled, functions with more than one output generate a
+    /// dedicated `#[derive(Clone, Debug)]` return struct instead of an
+    /// anonymous tuple. Disabled by default so existing generated code is
+    /// unaffected.
+    pub fn generate_output_structs(mut self, generate_output_structs: bool) -> Self {
+        self.generate_output_structs = generate_output_structs;
+        self
+    }
+
+    /// Generates the Rust bindings for `contract`.
+    pub fn generate(&self, contract: &Contract) -> Result<GeneratedContract> {
+        let cx = Context {
+            contract: contract.clone(),
+            method_aliases: self.method_aliases.clone(),
+            generate_output_structs: self.generate_output_structs,
+        };
+        let tokens = generate::expand(&cx).context("error generating contract bindings")?;
+        Ok(GeneratedContract { tokens })
+    }
+}
+
+/// The generated Rust bindings for a single contract, ready to be written
+/// out to a file (typically from a `build.rs` script).
+pub struct GeneratedContract {
+    tokens: TokenStream,
+}
+
+impl GeneratedContract {
+    /// Appends the generated bindings to the file at `path`, creating it if
+    /// it does not already exist.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("error opening output file '{}'", path.display()))?;
+        writeln!(file, "{}", self.tokens)
+            .with_context(|| format!("error writing generated bindings to '{}'", path.display()))?;
+        Ok(())
+    }
+}
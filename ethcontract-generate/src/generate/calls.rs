@@ -0,0 +1,250 @@
+use crate::generate::methods::{expand_function_names, expand_selector};
+use crate::generate::{types, Context};
+use crate::util;
+use anyhow::{Context as _, Result};
+use ethcontract_common::abi::{Function, ParamType};
+use ethcontract_common::abiext::FunctionExt;
+use inflector::Inflector;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// Expands a context into one struct per ABI function plus a top-level
+/// `Calls` enum, allowing raw calldata (e.g. from a pending transaction or a
+/// multicall payload) to be decoded back into typed arguments.
+pub(crate) fn expand(cx: &Context) -> Result<TokenStream> {
+    let functions = cx.contract.abi.functions().collect::<Vec<_>>();
+    if functions.is_empty() {
+        return Ok(quote! {});
+    }
+
+    // Calldata structs get their own names, but reuse the same overload
+    // disambiguation as the method builders so overloaded functions still
+    // produce distinct struct names.
+    let names = expand_function_names(&functions, &vec![None; functions.len()])
+        .into_iter()
+        .map(|name| format_ident!("{}Call", name.to_string().to_pascal_case()))
+        .collect::<Vec<_>>();
+
+    let structs = functions
+        .iter()
+        .zip(&names)
+        .map(|(&function, name)| expand_call_struct(function, name))
+        .collect::<Result<Vec<_>>>()?;
+
+    let variants = names.iter().map(|name| quote! { #name(#name) });
+    let decode_arms = functions.iter().zip(&names).map(|(function, name)| {
+        let pattern = expand_selector_pattern(function.selector());
+        quote! { #pattern => #name::decode(data).map(Calls::#name) }
+    });
+
+    Ok(quote! {
+        #( #structs )*
+
+        /// A decoded call into one of this contract's functions, dispatched
+        /// on the leading 4-byte function selector.
+        #[derive(Clone, Debug)]
+        pub enum Calls {
+            #( #variants ),*
+        }
+
+        impl Calls {
+            /// Decodes raw calldata into the matching `Calls` variant based
+            /// on its leading function selector.
+            pub fn decode(data: &[u8]) -> std::result::Result<Self, CallDecodeError> {
+                if data.len() < 4 {
+                    return Err(CallDecodeError::new("calldata is truncated: fewer than 4 bytes"));
+                }
+                match data {
+                    #( #decode_arms, )*
+                    _ => Err(CallDecodeError::new("calldata does not match any known function selector")),
+                }
+            }
+        }
+
+        /// An error decoding calldata into one of this contract's call
+        /// structs or into the `Calls` enum.
+        #[derive(Debug)]
+        pub struct CallDecodeError(String);
+
+        impl CallDecodeError {
+            fn new(message: impl Into<String>) -> Self {
+                CallDecodeError(message.into())
+            }
+        }
+
+        impl std::fmt::Display for CallDecodeError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl std::error::Error for CallDecodeError {}
+
+        impl From<self::ethcontract::common::abi::Error> for CallDecodeError {
+            fn from(err: self::ethcontract::common::abi::Error) -> Self {
+                CallDecodeError::new(err.to_string())
+            }
+        }
+    })
+}
+
+fn expand_call_struct(function: &Function, name: &Ident) -> Result<TokenStream> {
+    let signature = function.abi_signature();
+
+    let field_names = function
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, param)| util::expand_input_name(i, &param.name))
+        .collect::<Vec<_>>();
+    let field_types = function
+        .inputs
+        .iter()
+        .map(|param| types::expand(&param.kind))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("error expanding call struct for function '{}'", signature))?;
+    let param_types = function
+        .inputs
+        .iter()
+        .map(|param| expand_param_type(&param.kind))
+        .collect::<Vec<_>>();
+
+    let selector = expand_selector(function.selector());
+    let doc = util::expand_doc(&format!("`{}` calldata.", signature));
+
+    Ok(quote! {
+        #doc
+        #[derive(Clone, Debug)]
+        pub struct #name {
+            #( pub #field_names: #field_types ),*
+        }
+
+        impl #name {
+            /// ABI-encodes this call, including its 4-byte function selector.
+            pub fn encode(&self) -> Vec<u8> {
+                let mut data = (#selector).to_vec();
+                let tokens = vec![
+                    #( self::ethcontract::tokens::Tokenizable::into_token(self.#field_names.clone()) ),*
+                ];
+                data.extend(self::ethcontract::common::abi::encode(&tokens));
+                data
+            }
+
+            /// Decodes calldata into this call's typed arguments, validating
+            /// the leading function selector.
+            pub fn decode(data: &[u8]) -> std::result::Result<Self, CallDecodeError> {
+                if data.len() < 4 || data[..4] != (#selector) {
+                    return Err(CallDecodeError::new(format!(
+                        "calldata selector does not match `{}`",
+                        #signature,
+                    )));
+                }
+                let param_types = [ #( #param_types ),* ];
+                let mut tokens =
+                    self::ethcontract::common::abi::decode(&param_types, &data[4..])?.into_iter();
+                Ok(Self {
+                    #( #field_names: self::ethcontract::tokens::Tokenizable::from_token(
+                        tokens.next().expect("ethabi decoded fewer tokens than function inputs")
+                    )
+                    .map_err(|err| CallDecodeError::new(err.to_string()))?, )*
+                })
+            }
+        }
+    })
+}
+
+fn expand_selector_pattern(selector: ethcontract_common::hash::H32) -> TokenStream {
+    let bytes = selector.iter().copied().map(Literal::u8_unsuffixed);
+    quote! { [#( #bytes ),*, ..] }
+}
+
+/// Reconstructs a `ParamType` value so it can be used at runtime with
+/// `ethabi`'s `decode`, which needs the parameter types rather than the
+/// generated Rust types.
+fn expand_param_type(kind: &ParamType) -> TokenStream {
+    let size = |n: &usize| Literal::usize_unsuffixed(*n);
+    match kind {
+        ParamType::Address => quote! { self::ethcontract::common::abi::ParamType::Address },
+        ParamType::Bytes => quote! { self::ethcontract::common::abi::ParamType::Bytes },
+        ParamType::Int(n) => {
+            let size = size(n);
+            quote! { self::ethcontract::common::abi::ParamType::Int(#size) }
+        }
+        ParamType::Uint(n) => {
+            let size = size(n);
+            quote! { self::ethcontract::common::abi::ParamType::Uint(#size) }
+        }
+        ParamType::Bool => quote! { self::ethcontract::common::abi::ParamType::Bool },
+        ParamType::String => quote! { self::ethcontract::common::abi::ParamType::String },
+        ParamType::FixedBytes(n) => {
+            let size = size(n);
+            quote! { self::ethcontract::common::abi::ParamType::FixedBytes(#size) }
+        }
+        ParamType::Array(inner) => {
+            let inner = expand_param_type(inner);
+            quote! { self::ethcontract::common::abi::ParamType::Array(Box::new(#inner)) }
+        }
+        ParamType::FixedArray(inner, n) => {
+            let inner = expand_param_type(inner);
+            let size = size(n);
+            quote! { self::ethcontract::common::abi::ParamType::FixedArray(Box::new(#inner), #size) }
+        }
+        ParamType::Tuple(inners) => {
+            let inners = inners.iter().map(expand_param_type);
+            quote! { self::ethcontract::common::abi::ParamType::Tuple(vec![#( #inners ),*]) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_param_type_scalar() {
+        assert_quote!(
+            expand_param_type(&ParamType::Address),
+            { self::ethcontract::common::abi::ParamType::Address },
+        );
+        assert_quote!(
+            expand_param_type(&ParamType::Uint(256)),
+            { self::ethcontract::common::abi::ParamType::Uint(256) },
+        );
+    }
+
+    #[test]
+    fn expand_param_type_array() {
+        assert_quote!(
+            expand_param_type(&ParamType::Array(Box::new(ParamType::Address))),
+            {
+                self::ethcontract::common::abi::ParamType::Array(Box::new(
+                    self::ethcontract::common::abi::ParamType::Address
+                ))
+            },
+        );
+    }
+
+    #[test]
+    fn expand_param_type_tuple() {
+        assert_quote!(
+            expand_param_type(&ParamType::Tuple(vec![ParamType::Bool, ParamType::Uint(256)])),
+            {
+                self::ethcontract::common::abi::ParamType::Tuple(vec![
+                    self::ethcontract::common::abi::ParamType::Bool,
+                    self::ethcontract::common::abi::ParamType::Uint(256)
+                ])
+            },
+        );
+    }
+
+    #[test]
+    fn expand_selector_pattern_matches_leading_bytes_and_ignores_rest() {
+        let selector: ethcontract_common::hash::H32 = [0xaa, 0xbb, 0xcc, 0xdd];
+
+        assert_quote!(
+            expand_selector_pattern(selector),
+            { [170, 187, 204, 221, ..] },
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use ethcontract_common::Contract;
+use std::collections::HashMap;
+use syn::Ident;
+
+/// Shared context threaded through the various `expand` functions used to
+/// generate a contract's Rust bindings.
+pub(crate) struct Context {
+    /// The contract artifact (ABI plus dev/user documentation) to generate
+    /// bindings for.
+    pub(crate) contract: Contract,
+    /// Manual method name overrides, keyed by the method's ABI signature.
+    pub(crate) method_aliases: HashMap<String, Ident>,
+    /// Whether multi-output functions generate a dedicated named return
+    /// struct instead of an anonymous tuple. See
+    /// [`ContractBuilder::generate_output_structs`][crate::ContractBuilder::generate_output_structs].
+    pub(crate) generate_output_structs: bool,
+}
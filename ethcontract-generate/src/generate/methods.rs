@@ -1,21 +1,25 @@
+use crate::generate::calls;
 use crate::generate::{types, Context};
 use crate::util;
 use anyhow::{anyhow, Context as _, Result};
-use ethcontract_common::abi::{Function, Param, StateMutability};
+use ethcontract_common::abi::{Function, Param, ParamType, StateMutability};
 use ethcontract_common::abiext::FunctionExt;
 use ethcontract_common::hash::H32;
 use inflector::Inflector;
 use proc_macro2::{Literal, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
 use syn::Ident;
 
 pub(crate) fn expand(cx: &Context) -> Result<TokenStream> {
     let functions = expand_functions(cx)?;
     let fallback = expand_fallback(cx);
+    let calls = calls::expand(cx)?;
 
     Ok(quote! {
         #functions
         #fallback
+        #calls
     })
 }
 
@@ -23,37 +27,60 @@ pub(crate) fn expand(cx: &Context) -> Result<TokenStream> {
 /// to the Solidity contract methods.
 fn expand_functions(cx: &Context) -> Result<TokenStream> {
     let mut aliases = cx.method_aliases.clone();
-    let functions = cx
-        .contract
-        .abi
-        .functions()
-        .map(|function| {
-            let signature = function.abi_signature();
-
-            let alias = aliases.remove(&signature);
-            let name = alias.unwrap_or_else(|| util::safe_ident(&function.name.to_snake_case()));
-            let signature = function.abi_signature();
+    let abi_functions = cx.contract.abi.functions().collect::<Vec<_>>();
+    let signatures = abi_functions
+        .iter()
+        .map(|function| function.abi_signature())
+        .collect::<Vec<_>>();
+    let manual_aliases = signatures
+        .iter()
+        .map(|signature| aliases.remove(signature))
+        .collect::<Vec<_>>();
+    if let Some(unused) = aliases.keys().next() {
+        return Err(anyhow!(
+            "a manual method alias for '{}' was specified but this method does not exist",
+            unused,
+        ));
+    }
+    let names = expand_function_names(&abi_functions, &manual_aliases);
+
+    let functions = abi_functions
+        .iter()
+        .zip(signatures)
+        .zip(names)
+        .map(|((function, signature), name)| {
             let selector = expand_selector(function.selector());
             let inputs = expand_inputs(&function.inputs)
                 .with_context(|| format!("error expanding function '{}'", signature))?;
             let input_types = expand_input_types(&function.inputs)
                 .with_context(|| format!("error expanding function '{}'", signature))?;
-            let outputs = expand_outputs(&function.outputs)
+            let output_tuple = expand_outputs(&function.outputs)
                 .with_context(|| format!("error expanding function '{}'", signature))?;
-
-            Ok((function, name, selector, inputs, input_types, outputs))
+            let output_struct = if cx.generate_output_structs && function.outputs.len() > 1 {
+                Some(
+                    expand_output_struct(&name, *function, &output_tuple)
+                        .with_context(|| format!("error expanding function '{}'", signature))?,
+                )
+            } else {
+                None
+            };
+            let outputs = match &output_struct {
+                Some((_, struct_name)) => quote! { #struct_name },
+                None => output_tuple,
+            };
+            let output_struct_def = output_struct.map(|(def, _)| def);
+
+            Ok((*function, name, selector, inputs, input_types, outputs, output_struct_def))
         })
         .collect::<Result<Vec<_>>>()?;
-    if let Some(unused) = aliases.keys().next() {
-        return Err(anyhow!(
-            "a manual method alias for '{}' was specified but this method does not exist",
-            unused,
-        ));
-    }
+
+    let output_structs = functions
+        .iter()
+        .filter_map(|(.., output_struct_def)| output_struct_def.as_ref());
 
     let methods = functions
         .iter()
-        .map(|(function, name, selector, inputs, _, outputs)| {
+        .map(|(function, name, selector, inputs, _, outputs, _)| {
             expand_function(cx, function, name, selector, inputs, outputs)
         });
 
@@ -67,7 +94,7 @@ fn expand_functions(cx: &Context) -> Result<TokenStream> {
     let signature_accessors =
         functions
             .iter()
-            .map(|(function, name, selector, _, input_types, outputs)| {
+            .map(|(function, name, selector, _, input_types, outputs, _)| {
                 expand_signature_accessor(function, name, selector, input_types, outputs)
             });
 
@@ -105,6 +132,8 @@ fn expand_functions(cx: &Context) -> Result<TokenStream> {
             }
         }
 
+        #( #output_structs )*
+
         /// Type containing signatures for all methods for generated contract type.
         #signatures_attrs
         pub #signatures_struct
@@ -131,6 +160,101 @@ fn expand_functions(cx: &Context) -> Result<TokenStream> {
     })
 }
 
+/// Computes the Rust identifier to use for each ABI function, disambiguating
+/// overloaded functions (same Solidity name, different parameters) that would
+/// otherwise generate duplicate method names.
+///
+/// Functions that have a manual alias keep it as-is and are left out of the
+/// overload grouping. Functions that share a snake_case name with at least one
+/// other function without a manual alias are suffixed with their
+/// canonical input types (e.g. `safe_transfer_from_address_address_uint256`).
+/// If more than three functions share a name, or the type-suffixed names
+/// still collide, a stable numeric suffix in ABI declaration order is used
+/// instead (e.g. `log_0`, `log_1`).
+pub(crate) fn expand_function_names(
+    functions: &[&Function],
+    manual_aliases: &[Option<Ident>],
+) -> Vec<Ident> {
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, function) in functions.iter().enumerate() {
+        if manual_aliases[i].is_none() {
+            groups
+                .entry(function.name.to_snake_case())
+                .or_default()
+                .push(i);
+        }
+    }
+
+    let mut names = vec![None; functions.len()];
+    for (i, alias) in manual_aliases.iter().enumerate() {
+        names[i] = alias.clone();
+    }
+
+    for (base_name, indices) in groups {
+        if indices.len() == 1 {
+            names[indices[0]] = Some(util::safe_ident(&base_name));
+            continue;
+        }
+
+        let type_suffixed = if indices.len() <= 3 {
+            let candidates = indices
+                .iter()
+                .map(|&i| {
+                    let suffix = functions[i]
+                        .inputs
+                        .iter()
+                        .map(|param| expand_type_name_fragment(&param.kind))
+                        .collect::<Vec<_>>()
+                        .join("_");
+                    if suffix.is_empty() {
+                        base_name.clone()
+                    } else {
+                        format!("{}_{}", base_name, suffix)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let mut unique = HashSet::new();
+            if candidates.iter().all(|name| unique.insert(name.clone())) {
+                Some(candidates)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        match type_suffixed {
+            Some(candidates) => {
+                for (&i, name) in indices.iter().zip(candidates) {
+                    names[i] = Some(util::safe_ident(&name));
+                }
+            }
+            None => {
+                for (n, &i) in indices.iter().enumerate() {
+                    names[i] = Some(util::safe_ident(&format!("{}_{}", base_name, n)));
+                }
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| name.expect("every function has a resolved name"))
+        .collect()
+}
+
+/// Renders a Solidity parameter type as an identifier-safe fragment, suitable
+/// for disambiguating overloaded function names (e.g. `uint256[]` becomes
+/// `uint256_array`).
+fn expand_type_name_fragment(kind: &ParamType) -> String {
+    kind.to_string()
+        .replace("[]", "_array")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
 fn expand_function(
     cx: &Context,
     function: &Function,
@@ -233,7 +357,66 @@ fn expand_outputs(outputs: &[Param]) -> Result<TokenStream> {
     }
 }
 
-fn expand_selector(selector: H32) -> TokenStream {
+/// Expands a dedicated return struct for a multi-output function, with one
+/// field per output (falling back to `field_0`, `field_1`, … for outputs
+/// without a name), together with the type name to use in place of the
+/// anonymous output tuple. Only used when `Context::generate_output_structs`
+/// is enabled.
+fn expand_output_struct(
+    name: &Ident,
+    function: &Function,
+    output_tuple: &TokenStream,
+) -> Result<(TokenStream, Ident)> {
+    let signature = function.abi_signature();
+    let struct_name = format_ident!("{}Return", name.to_string().to_pascal_case());
+
+    let field_names = function
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(i, param)| expand_output_field_name(i, &param.name))
+        .collect::<Vec<_>>();
+    let field_types = function
+        .outputs
+        .iter()
+        .map(|param| types::expand(&param.kind))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("error expanding return struct for function '{}'", signature))?;
+
+    let doc = util::expand_doc(&format!("Return type for `{}`.", signature));
+
+    let def = quote! {
+        #doc
+        #[derive(Clone, Debug)]
+        pub struct #struct_name {
+            #( pub #field_names: #field_types ),*
+        }
+
+        impl self::ethcontract::tokens::Detokenize for #struct_name {
+            fn from_tokens(
+                tokens: Vec<self::ethcontract::common::abi::Token>,
+            ) -> Result<Self, self::ethcontract::tokens::Error> {
+                let ( #( #field_names ),* ,) =
+                    <#output_tuple as self::ethcontract::tokens::Detokenize>::from_tokens(tokens)?;
+                Ok(Self { #( #field_names ),* })
+            }
+        }
+    };
+
+    Ok((def, struct_name))
+}
+
+/// Computes the field name for a function output, falling back to
+/// `field_0`, `field_1`, … for outputs without a Solidity name.
+fn expand_output_field_name(index: usize, name: &str) -> Ident {
+    if name.is_empty() {
+        util::safe_ident(&format!("field_{}", index))
+    } else {
+        util::safe_ident(&name.to_snake_case())
+    }
+}
+
+pub(crate) fn expand_selector(selector: H32) -> TokenStream {
     let bytes = selector.iter().copied().map(Literal::u8_unsuffixed);
     quote! { [#( #bytes ),*] }
 }
@@ -325,4 +508,69 @@ mod tests {
             { (bool, self::ethcontract::Address) },
         );
     }
+
+    #[allow(deprecated)]
+    fn function(name: &str, inputs: &[ParamType]) -> Function {
+        Function {
+            name: name.to_string(),
+            inputs: inputs
+                .iter()
+                .map(|kind| Param {
+                    name: String::new(),
+                    kind: kind.clone(),
+                })
+                .collect(),
+            outputs: Vec::new(),
+            constant: None,
+            state_mutability: StateMutability::NonPayable,
+        }
+    }
+
+    #[test]
+    fn expand_function_names_overload_uses_type_suffix() {
+        let foo_bool = function("foo", &[ParamType::Bool]);
+        let foo_address = function("foo", &[ParamType::Address]);
+        let functions = [&foo_bool, &foo_address];
+
+        let names = expand_function_names(&functions, &[None, None]);
+
+        assert_eq!(names[0].to_string(), "foo_bool");
+        assert_eq!(names[1].to_string(), "foo_address");
+    }
+
+    #[test]
+    fn expand_function_names_many_overloads_use_numeric_fallback() {
+        let overloads = [
+            function("log", &[ParamType::Bool]),
+            function("log", &[ParamType::Address]),
+            function("log", &[ParamType::Uint(256)]),
+            function("log", &[ParamType::String]),
+        ];
+        let functions = overloads.iter().collect::<Vec<_>>();
+        let manual_aliases = vec![None; functions.len()];
+
+        let names = expand_function_names(&functions, &manual_aliases)
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["log_0", "log_1", "log_2", "log_3"]);
+    }
+
+    #[test]
+    fn expand_function_names_manual_alias_removes_function_from_group() {
+        let foo_bool = function("foo", &[ParamType::Bool]);
+        let foo_address = function("foo", &[ParamType::Address]);
+        let functions = [&foo_bool, &foo_address];
+
+        // `foo_bool` is manually aliased, so only `foo_address` remains in
+        // the overload group and keeps the plain `foo` name instead of
+        // being disambiguated by its input types.
+        let manual_aliases = [Some(util::safe_ident("foo_aliased")), None];
+
+        let names = expand_function_names(&functions, &manual_aliases);
+
+        assert_eq!(names[0].to_string(), "foo_aliased");
+        assert_eq!(names[1].to_string(), "foo");
+    }
 }
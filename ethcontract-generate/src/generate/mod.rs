@@ -0,0 +1,20 @@
+mod calls;
+mod context;
+mod methods;
+pub(crate) mod types;
+
+pub(crate) use context::Context;
+
+use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Expands a context into the full token stream of generated contract
+/// bindings.
+pub(crate) fn expand(cx: &Context) -> Result<TokenStream> {
+    let methods = methods::expand(cx)?;
+
+    Ok(quote! {
+        #methods
+    })
+}
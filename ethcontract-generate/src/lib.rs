@@ -0,0 +1,5 @@
+mod contract;
+mod generate;
+pub mod loaders;
+
+pub use contract::{ContractBuilder, GeneratedContract};
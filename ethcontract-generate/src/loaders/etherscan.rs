@@ -0,0 +1,126 @@
+use crate::loaders::Artifact;
+use anyhow::{anyhow, Context as _, Result};
+use ethcontract_common::Contract;
+use serde::Deserialize;
+
+/// The network (or Etherscan-compatible block explorer) to fetch a
+/// contract's ABI from.
+#[derive(Clone, Debug)]
+pub enum Network {
+    Mainnet,
+    Goerli,
+    Sepolia,
+    /// A custom Etherscan-compatible explorer (e.g. Polygonscan, BscScan),
+    /// given its API base URL such as `https://api.polygonscan.com`.
+    Custom(String),
+}
+
+impl Network {
+    fn api_base_url(&self) -> &str {
+        match self {
+            Network::Mainnet => "https://api.etherscan.io",
+            Network::Goerli => "https://api-goerli.etherscan.io",
+            Network::Sepolia => "https://api-sepolia.etherscan.io",
+            Network::Custom(base_url) => base_url,
+        }
+    }
+}
+
+/// Loads a contract artifact by fetching a verified contract's ABI from
+/// Etherscan (or an Etherscan-compatible block explorer) by address.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ethcontract_generate::loaders::{EtherscanLoader, Network};
+/// let artifact = EtherscanLoader::new()
+///     .api_key("...")
+///     .network(Network::Mainnet)
+///     .load_from_address("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")
+///     .unwrap();
+/// ```
+pub struct EtherscanLoader {
+    api_key: Option<String>,
+    network: Network,
+}
+
+impl EtherscanLoader {
+    /// Creates a new loader targeting the Ethereum mainnet.
+    pub fn new() -> Self {
+        EtherscanLoader {
+            api_key: None,
+            network: Network::Mainnet,
+        }
+    }
+
+    /// Sets the Etherscan API key to use for requests.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the network (or Etherscan-compatible explorer) to fetch the
+    /// contract ABI from.
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Fetches and parses the verified ABI for the contract at `address`.
+    ///
+    /// Returns a single-element [`Artifact`], so it can be fed into the same
+    /// `for contract in artifact.iter() { ... }` pattern used for the other
+    /// loaders.
+    pub fn load_from_address(&self, address: &str) -> Result<Artifact> {
+        let api_key = self
+            .api_key
+            .as_deref()
+            .context("an Etherscan API key is required to load a contract ABI")?;
+        let url = format!(
+            "{}/api?module=contract&action=getabi&address={}&apikey={}",
+            self.network.api_base_url(),
+            address,
+            api_key,
+        );
+
+        let response: GetAbiResponse = ureq::get(&url)
+            .call()
+            .with_context(|| format!("error requesting ABI for contract '{}' from Etherscan", address))?
+            .into_json()
+            .context("error parsing Etherscan response")?;
+
+        if response.status != "1" {
+            return Err(anyhow!(
+                "Etherscan could not provide a verified ABI for contract '{}': {} ({})",
+                address,
+                response.message,
+                response.result,
+            ));
+        }
+
+        let abi = ethcontract_common::abi::Contract::load(response.result.as_bytes())
+            .with_context(|| format!("error parsing ABI returned for contract '{}'", address))?;
+
+        Ok(vec![Contract {
+            abi,
+            ..Contract::default()
+        }])
+    }
+}
+
+impl Default for EtherscanLoader {
+    fn default() -> Self {
+        EtherscanLoader::new()
+    }
+}
+
+/// The shape of Etherscan's `getabi` JSON response.
+#[derive(Deserialize)]
+struct GetAbiResponse {
+    status: String,
+    message: String,
+    /// On success, the contract's ABI as a JSON-encoded string. On failure
+    /// (rate limiting, an unverified contract, ...), a human readable
+    /// explanation.
+    result: String,
+}
@@ -0,0 +1,82 @@
+use crate::loaders::Artifact;
+use anyhow::{Context as _, Result};
+use ethcontract_common::Contract;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Loads contract artifacts from a Hardhat `deployments` directory, where
+/// each network has its own subdirectory of per-contract JSON artifacts.
+pub struct HardHatLoader {
+    denied_networks: HashSet<String>,
+}
+
+impl HardHatLoader {
+    /// Creates a new loader that includes every network found in the
+    /// deployments directory.
+    pub fn new() -> Self {
+        HardHatLoader {
+            denied_networks: HashSet::new(),
+        }
+    }
+
+    /// Excludes a network (by its deployments subdirectory name) from the
+    /// loaded artifacts, e.g. to skip locally deployed contracts.
+    pub fn deny_network_by_name(mut self, name: impl Into<String>) -> Self {
+        self.denied_networks.insert(name.into());
+        self
+    }
+
+    /// Loads every contract artifact found in `directory`, skipping denied
+    /// networks.
+    pub fn load_from_directory(&self, directory: impl AsRef<Path>) -> Result<Artifact> {
+        let directory = directory.as_ref();
+        let mut contracts = Vec::new();
+
+        let network_dirs = fs::read_dir(directory).with_context(|| {
+            format!(
+                "error reading deployments directory '{}'",
+                directory.display()
+            )
+        })?;
+        for network_entry in network_dirs {
+            let network_dir = network_entry?.path();
+            if !network_dir.is_dir() {
+                continue;
+            }
+            let network_name = network_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            if self.denied_networks.contains(network_name) {
+                continue;
+            }
+
+            let contract_entries = fs::read_dir(&network_dir).with_context(|| {
+                format!(
+                    "error reading network directory '{}'",
+                    network_dir.display()
+                )
+            })?;
+            for contract_entry in contract_entries {
+                let path = contract_entry?.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    continue;
+                }
+                let json = fs::read(&path)
+                    .with_context(|| format!("error reading artifact '{}'", path.display()))?;
+                let contract = Contract::from_json(&json)
+                    .with_context(|| format!("error parsing artifact '{}'", path.display()))?;
+                contracts.push(contract);
+            }
+        }
+
+        Ok(contracts)
+    }
+}
+
+impl Default for HardHatLoader {
+    fn default() -> Self {
+        HardHatLoader::new()
+    }
+}
@@ -0,0 +1,11 @@
+//! Loaders for contract artifacts from various sources, for use ahead of code
+//! generation (e.g. from a `build.rs` script).
+
+mod etherscan;
+mod hardhat;
+
+pub use etherscan::{EtherscanLoader, Network};
+pub use hardhat::HardHatLoader;
+
+/// A collection of contract artifacts loaded from a single source.
+pub type Artifact = Vec<ethcontract_common::Contract>;